@@ -0,0 +1,355 @@
+//! An error-handling audit over parsed function bodies.
+//!
+//! Rust has no checked exceptions: a `panic!` (or an `unwrap`/`expect`/
+//! `unreachable!` that reaches one) unwinds the stack with no recovery at the
+//! call site, whereas a function that returns `Result`/`Option` surfaces
+//! failure as an ordinary value the caller must handle. This pass walks every
+//! function and method and classifies it as:
+//!
+//! * [`ErrorBehavior::Panicking`] — its body contains a panic point;
+//! * [`ErrorBehavior::Fallible`] — it returns `Result<_, _>` or `Option<_>`
+//!   and has no panic point;
+//! * [`ErrorBehavior::Infallible`] — neither of the above.
+//!
+//! Each panic point is reported with its byte span so a codebase audit can
+//! jump straight to the hidden failure site.
+
+use crate::lexer::{tokenize, Token, TokenKind};
+use crate::symbol::{Symbol, SymbolKind};
+
+/// How a function surfaces failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorBehavior {
+    /// Contains a `panic!`/`unwrap`/`expect`/`unreachable!` point.
+    Panicking,
+    /// Returns `Result<_, _>` or `Option<_>` and never panics.
+    Fallible,
+    /// Returns neither and never panics.
+    Infallible,
+}
+
+impl ErrorBehavior {
+    /// A stable lower-case label for the report.
+    pub fn label(self) -> &'static str {
+        match self {
+            ErrorBehavior::Panicking => "panicking",
+            ErrorBehavior::Fallible => "fallible",
+            ErrorBehavior::Infallible => "infallible",
+        }
+    }
+}
+
+/// The kind of panic point found in a body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicKind {
+    /// A `panic!(...)` invocation.
+    Panic,
+    /// An `.unwrap()` call.
+    Unwrap,
+    /// An `.expect(...)` call.
+    Expect,
+    /// An `unreachable!(...)` invocation.
+    Unreachable,
+}
+
+impl PanicKind {
+    /// The source spelling, e.g. `panic!` or `unwrap`.
+    pub fn label(self) -> &'static str {
+        match self {
+            PanicKind::Panic => "panic!",
+            PanicKind::Unwrap => "unwrap",
+            PanicKind::Expect => "expect",
+            PanicKind::Unreachable => "unreachable!",
+        }
+    }
+}
+
+/// A single panic point with its byte span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PanicSite {
+    pub kind: PanicKind,
+    /// Byte offset of the first character of the call.
+    pub start: usize,
+    /// Byte offset one past the macro's `!` or the method name.
+    pub end: usize,
+}
+
+/// The audit result for a single function or method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionAudit {
+    /// The function or method name.
+    pub name: String,
+    /// Its classification.
+    pub behavior: ErrorBehavior,
+    /// Whether the signature returns `Result<_, _>`.
+    pub returns_result: bool,
+    /// Whether the signature returns `Option<_>`.
+    pub returns_option: bool,
+    /// Every panic point discovered in the body, in source order.
+    pub panics: Vec<PanicSite>,
+    /// Byte offset of the function's first token.
+    pub start: usize,
+    /// Byte offset one past its closing brace.
+    pub end: usize,
+}
+
+/// Audit every function and method in `src`, in source order.
+pub fn audit(src: &str) -> Vec<FunctionAudit> {
+    let tokens = tokenize(src);
+    let symbols = crate::parse(src);
+    let mut funcs = Vec::new();
+    collect_functions(&symbols, &mut funcs);
+    funcs
+        .into_iter()
+        .map(|sym| audit_function(sym, &tokens))
+        .collect()
+}
+
+/// Collect functions and methods from the symbol tree, in source order.
+fn collect_functions<'a>(symbols: &'a [Symbol], out: &mut Vec<&'a Symbol>) {
+    for sym in symbols {
+        if matches!(sym.kind, SymbolKind::Function | SymbolKind::Method) {
+            out.push(sym);
+        }
+        collect_functions(&sym.children, out);
+    }
+}
+
+/// Audit a single function symbol against the full token stream.
+fn audit_function(sym: &Symbol, tokens: &[Token]) -> FunctionAudit {
+    // The tokens covering this function, `[lo, hi)`.
+    let lo = tokens.partition_point(|t| t.start < sym.start);
+    let hi = tokens.partition_point(|t| t.end <= sym.end);
+    let (body, returns_result, returns_option) = scan_signature(tokens, lo, hi);
+    let panics = body
+        .map(|b| scan_panics(tokens, b, hi))
+        .unwrap_or_default();
+    let behavior = if !panics.is_empty() {
+        ErrorBehavior::Panicking
+    } else if returns_result || returns_option {
+        ErrorBehavior::Fallible
+    } else {
+        ErrorBehavior::Infallible
+    };
+    FunctionAudit {
+        name: sym.name.clone(),
+        behavior,
+        returns_result,
+        returns_option,
+        panics,
+        start: sym.start,
+        end: sym.end,
+    }
+}
+
+/// Scan the signature in `[lo, hi)` for the body `{`, and read whether the
+/// return type's leading path is `Result` or `Option`. Returns the body token
+/// index (if any) alongside those two flags.
+fn scan_signature(tokens: &[Token], lo: usize, hi: usize) -> (Option<usize>, bool, bool) {
+    let mut depth = 0i32;
+    let mut angle = 0i32;
+    let mut arrow = None;
+    let mut where_seen = false;
+    let mut i = lo;
+    while i < hi {
+        match tokens[i].text.as_str() {
+            "(" | "[" => depth += 1,
+            ")" | "]" => depth -= 1,
+            // A `->` inside a generic bound such as `F: Fn() -> T` belongs to
+            // that bound, not the function; the first top-level arrow is the
+            // return arrow (a later one lives inside the return type, e.g.
+            // `-> impl Fn() -> T`). A `where`-clause closure bound sits at
+            // depth 0 / angle 0 too, so stop capturing once `where` is seen;
+            // the real return arrow always precedes the `where`.
+            "->" if depth == 0 && angle == 0 && !where_seen && arrow.is_none() => arrow = Some(i),
+            "where" if depth == 0 && angle == 0 && tokens[i].kind == TokenKind::Ident => {
+                where_seen = true;
+            }
+            "{" if depth == 0 => {
+                let ret = arrow.map(|a| return_base(tokens, a + 1, i));
+                return (
+                    Some(i),
+                    ret.as_deref() == Some("Result"),
+                    ret.as_deref() == Some("Option"),
+                );
+            }
+            ";" if depth == 0 => break,
+            _ => angle += angle_delta(&tokens[i]),
+        }
+        i += 1;
+    }
+    let ret = arrow.map(|a| return_base(tokens, a + 1, hi));
+    (
+        None,
+        ret.as_deref() == Some("Result"),
+        ret.as_deref() == Some("Option"),
+    )
+}
+
+/// The final path segment of the return type in `[lo, hi)`, ignoring leading
+/// references and generic arguments: `std::io::Result<T>` -> `Result`,
+/// `&mut Option<i32>` -> `Option`. Stops at the first `<` or `where`.
+fn return_base(tokens: &[Token], lo: usize, hi: usize) -> String {
+    let mut name = String::new();
+    let mut i = lo;
+    while i < hi {
+        let t = &tokens[i];
+        if t.kind == TokenKind::Ident && t.text == "where" {
+            break;
+        }
+        if t.text == "<" {
+            break;
+        }
+        if t.kind == TokenKind::Ident && t.text != "dyn" {
+            name = t.text.clone();
+        }
+        i += 1;
+    }
+    name
+}
+
+/// The change in angle-bracket nesting contributed by `t`.
+fn angle_delta(t: &Token) -> i32 {
+    if t.kind != TokenKind::Punct {
+        return 0;
+    }
+    if t.text.chars().all(|c| c == '<') {
+        t.text.len() as i32
+    } else if t.text.chars().all(|c| c == '>') {
+        -(t.text.len() as i32)
+    } else {
+        0
+    }
+}
+
+/// Scan the body block whose `{` is at `body` for panic points, ignoring panic
+/// points that belong to a nested item (a `fn`/`mod`/`impl`/`trait` declared
+/// inside the body) so they are not attributed to the enclosing function.
+fn scan_panics(tokens: &[Token], body: usize, hi: usize) -> Vec<PanicSite> {
+    let mut sites = Vec::new();
+    let close = matching(tokens, body).min(hi);
+    let mut i = body + 1;
+    while i < close {
+        let t = &tokens[i];
+        if t.kind == TokenKind::Ident
+            && matches!(t.text.as_str(), "fn" | "mod" | "impl" | "trait")
+        {
+            // Skip the nested item so its panic points do not count here.
+            let mut j = i + 1;
+            while j < close && tokens[j].text != "{" && tokens[j].text != ";" {
+                j += 1;
+            }
+            i = if j < close && tokens[j].text == "{" {
+                matching(tokens, j)
+            } else {
+                j + 1
+            };
+            continue;
+        }
+        if t.kind == TokenKind::Ident {
+            // Macro-style panics: `panic!`, `unreachable!`.
+            if is_punct(tokens, i + 1, "!") {
+                let kind = match t.text.as_str() {
+                    "panic" => Some(PanicKind::Panic),
+                    "unreachable" => Some(PanicKind::Unreachable),
+                    _ => None,
+                };
+                if let Some(kind) = kind {
+                    sites.push(PanicSite {
+                        kind,
+                        start: t.start,
+                        end: tokens[i + 1].end,
+                    });
+                }
+            // Method-style panics: `.unwrap()`, `.expect(...)`.
+            } else if is_punct(tokens, i.wrapping_sub(1), ".") && i > 0 {
+                let kind = match t.text.as_str() {
+                    "unwrap" => Some(PanicKind::Unwrap),
+                    "expect" => Some(PanicKind::Expect),
+                    _ => None,
+                };
+                if let Some(kind) = kind {
+                    sites.push(PanicSite {
+                        kind,
+                        start: t.start,
+                        end: t.end,
+                    });
+                }
+            }
+        }
+        i += 1;
+    }
+    sites
+}
+
+/// Index one past the `}` matching the `{` at `open`.
+fn matching(tokens: &[Token], open: usize) -> usize {
+    let mut depth = 0i32;
+    let mut i = open;
+    while i < tokens.len() {
+        match tokens[i].text.as_str() {
+            "{" => depth += 1,
+            "}" => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    tokens.len()
+}
+
+fn is_punct(tokens: &[Token], i: usize, s: &str) -> bool {
+    tokens
+        .get(i)
+        .is_some_and(|t| t.kind == TokenKind::Punct && t.text == s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn behavior_of(src: &str, name: &str) -> ErrorBehavior {
+        audit(src)
+            .into_iter()
+            .find(|a| a.name == name)
+            .unwrap_or_else(|| panic!("no function named {name}"))
+            .behavior
+    }
+
+    #[test]
+    fn classifies_result_and_option_as_fallible() {
+        let src = "fn r() -> Result<u8, String> { Ok(0) }\n\
+                   fn o() -> Option<u8> { None }";
+        assert_eq!(behavior_of(src, "r"), ErrorBehavior::Fallible);
+        assert_eq!(behavior_of(src, "o"), ErrorBehavior::Fallible);
+    }
+
+    #[test]
+    fn classifies_plain_return_as_infallible() {
+        let src = "fn plain() -> u8 { 0 }\nfn unit() {}";
+        assert_eq!(behavior_of(src, "plain"), ErrorBehavior::Infallible);
+        assert_eq!(behavior_of(src, "unit"), ErrorBehavior::Infallible);
+    }
+
+    #[test]
+    fn panic_point_overrides_fallibility() {
+        let src = "fn p() -> Result<u8, String> { panic!(\"no\") }\n\
+                   fn u() -> u8 { something().unwrap() }";
+        assert_eq!(behavior_of(src, "p"), ErrorBehavior::Panicking);
+        assert_eq!(behavior_of(src, "u"), ErrorBehavior::Panicking);
+    }
+
+    #[test]
+    fn where_clause_closure_bound_is_not_the_return_arrow() {
+        // Both functions return `()`; the `->` lives inside a `where`-clause
+        // closure bound, not in the signature's return type.
+        let src = "fn h<F>(f: F) where F: Fn() -> Result<u8, String> {}\n\
+                   fn g<F>(f: F) where F: Fn() -> Option<u8> {}";
+        assert_eq!(behavior_of(src, "h"), ErrorBehavior::Infallible);
+        assert_eq!(behavior_of(src, "g"), ErrorBehavior::Infallible);
+    }
+}
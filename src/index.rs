@@ -0,0 +1,166 @@
+//! A reverse index over `impl` blocks: which types implement which traits, and
+//! which methods each impl contributes.
+//!
+//! Built from the flat symbol list returned by [`crate::parse`], it answers the
+//! two directions of the relation — implementors of a trait and traits of a
+//! type — and separates inherent methods (`Person::new`) from the methods that
+//! satisfy a trait.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::symbol::{Symbol, SymbolKind};
+
+/// A single `impl` block, classified as inherent or trait-implementing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImplBlock {
+    /// The implementing type, e.g. `Person` or `ObjectPool`.
+    pub type_name: String,
+    /// The trait being implemented, or `None` for an inherent `impl Type`.
+    pub trait_name: Option<String>,
+    /// Generic arguments applied to the type at the impl site, e.g. `["i32",
+    /// "i32"]` for `impl Point1<i32, i32>`.
+    pub type_args: Vec<String>,
+    /// Whether the impl introduces its own generic parameters (`impl<T> ...`).
+    pub generic: bool,
+    /// The names of the methods defined in this block.
+    pub methods: Vec<String>,
+}
+
+impl ImplBlock {
+    /// Whether this is an inherent `impl Type { ... }` rather than a trait impl.
+    pub fn is_inherent(&self) -> bool {
+        self.trait_name.is_none()
+    }
+}
+
+/// The trait-implementation index.
+#[derive(Debug, Clone, Default)]
+pub struct TraitIndex {
+    blocks: Vec<ImplBlock>,
+    /// trait name -> implementing type names.
+    implementors: BTreeMap<String, BTreeSet<String>>,
+    /// type name -> traits it implements.
+    implemented: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl TraitIndex {
+    /// Build the index from a parsed symbol list.
+    pub fn build(symbols: &[Symbol]) -> Self {
+        let mut index = TraitIndex::default();
+        for sym in symbols {
+            if sym.kind != SymbolKind::Impl {
+                continue;
+            }
+            let methods = sym
+                .children
+                .iter()
+                .filter(|c| c.kind == SymbolKind::Method)
+                .map(|c| c.name.clone())
+                .collect();
+            let block = ImplBlock {
+                type_name: sym.name.clone(),
+                trait_name: sym.trait_name.clone(),
+                type_args: sym.type_args.clone(),
+                generic: !sym.generics.params.is_empty(),
+                methods,
+            };
+            if let Some(trait_name) = &block.trait_name {
+                index
+                    .implementors
+                    .entry(trait_name.clone())
+                    .or_default()
+                    .insert(block.type_name.clone());
+                index
+                    .implemented
+                    .entry(block.type_name.clone())
+                    .or_default()
+                    .insert(trait_name.clone());
+            }
+            index.blocks.push(block);
+        }
+        index
+    }
+
+    /// Every impl block, in source order.
+    pub fn blocks(&self) -> &[ImplBlock] {
+        &self.blocks
+    }
+
+    /// The types that implement `trait_name`, sorted.
+    pub fn implementors_of(&self, trait_name: &str) -> Vec<&str> {
+        self.implementors
+            .get(trait_name)
+            .into_iter()
+            .flat_map(|set| set.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// The traits implemented by `type_name`, sorted.
+    pub fn traits_of(&self, type_name: &str) -> Vec<&str> {
+        self.implemented
+            .get(type_name)
+            .into_iter()
+            .flat_map(|set| set.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// The inherent methods of `type_name` (those in `impl Type` blocks).
+    pub fn inherent_methods(&self, type_name: &str) -> Vec<&str> {
+        self.blocks
+            .iter()
+            .filter(|b| b.is_inherent() && b.type_name == type_name)
+            .flat_map(|b| b.methods.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// The methods `type_name` defines to satisfy `trait_name`.
+    pub fn trait_methods(&self, type_name: &str, trait_name: &str) -> Vec<&str> {
+        self.blocks
+            .iter()
+            .filter(|b| b.type_name == type_name && b.trait_name.as_deref() == Some(trait_name))
+            .flat_map(|b| b.methods.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// All trait names that have at least one implementor, sorted.
+    pub fn traits(&self) -> Vec<&str> {
+        self.implementors.keys().map(String::as_str).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn indexes_multiple_implementors_of_one_trait() {
+        let src = "impl Draw for Square {}\nimpl Draw for Circle {}";
+        let index = TraitIndex::build(&parse(src));
+        assert_eq!(index.implementors_of("Draw"), ["Circle", "Square"]);
+        assert_eq!(index.traits_of("Square"), ["Draw"]);
+        assert_eq!(index.traits(), ["Draw"]);
+    }
+
+    #[test]
+    fn flags_generic_impl() {
+        let src = "impl<T> Pool for ObjectPool<T> { fn get(&self) {} }";
+        let index = TraitIndex::build(&parse(src));
+        let block = &index.blocks()[0];
+        assert!(block.generic);
+        assert_eq!(block.type_name, "ObjectPool");
+        assert_eq!(block.type_args, ["T"]);
+        assert_eq!(index.implementors_of("Pool"), ["ObjectPool"]);
+    }
+
+    #[test]
+    fn separates_inherent_from_trait_methods() {
+        let src = "impl Person { fn new() {} }\n\
+                   impl Greet for Person { fn hello(&self) {} }";
+        let index = TraitIndex::build(&parse(src));
+        assert_eq!(index.inherent_methods("Person"), ["new"]);
+        assert_eq!(index.trait_methods("Person", "Greet"), ["hello"]);
+        // The inherent method is not attributed to the trait, and vice versa.
+        assert!(index.trait_methods("Person", "Greet").iter().all(|m| *m != "new"));
+    }
+}
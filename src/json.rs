@@ -0,0 +1,232 @@
+//! A machine-readable export of the symbol table.
+//!
+//! [`to_json`] renders the nested [`Symbol`] tree as JSON so editors and
+//! cross-file indexers can consume an outline without re-parsing. Each node
+//! carries its kind, fully-qualified path (`Person::say_hello`), byte and line
+//! span, visibility, and its children, so a struct carries its fields, a trait
+//! its associated items, and an impl its methods.
+//!
+//! The crate pulls in no serialization dependency, so the JSON is written by
+//! hand, matching the forgiving, allocation-light style of the rest of `rsym`.
+
+use crate::symbol::Symbol;
+
+/// Render `symbols` (parsed from `src`) as a JSON array of symbol nodes.
+///
+/// `src` is needed to translate the byte offsets recorded on each symbol into
+/// 1-based line numbers.
+pub fn to_json(symbols: &[Symbol], src: &str) -> String {
+    let lines = LineMap::new(src);
+    let mut out = String::new();
+    write_nodes(symbols, None, &lines, 0, &mut out);
+    out
+}
+
+/// Write a `[...]` array of symbol nodes at the given indent level.
+fn write_nodes(symbols: &[Symbol], parent: Option<&str>, lines: &LineMap, depth: usize, out: &mut String) {
+    if symbols.is_empty() {
+        out.push_str("[]");
+        return;
+    }
+    out.push_str("[\n");
+    for (i, sym) in symbols.iter().enumerate() {
+        write_node(sym, parent, lines, depth + 1, out);
+        if i + 1 < symbols.len() {
+            out.push(',');
+        }
+        out.push('\n');
+    }
+    indent(depth, out);
+    out.push(']');
+}
+
+/// Write a single `{...}` object for `sym`.
+fn write_node(sym: &Symbol, parent: Option<&str>, lines: &LineMap, depth: usize, out: &mut String) {
+    let path = match parent {
+        Some(p) => format!("{p}::{}", sym.name),
+        None => sym.name.clone(),
+    };
+    let pad = "  ".repeat(depth);
+    indent(depth, out);
+    out.push_str("{\n");
+    field(&pad, "kind", sym.kind.label(), out);
+    field(&pad, "name", &sym.name, out);
+    field(&pad, "path", &path, out);
+    field(&pad, "visibility", &sym.visibility.label(), out);
+    if let Some(tr) = &sym.trait_name {
+        field(&pad, "trait", tr, out);
+    }
+    if !sym.type_args.is_empty() {
+        string_array(&pad, "type_args", &sym.type_args, out);
+    }
+    if let Some(ty) = &sym.ty {
+        field(&pad, "type", ty, out);
+    }
+    if let Some(value) = &sym.value {
+        field(&pad, "value", value, out);
+    }
+    if !sym.generics.is_empty() {
+        let params: Vec<_> = sym.generics.params.iter().map(|p| p.render()).collect();
+        string_array(&pad, "generics", &params, out);
+        let where_clause = sym.generics.render_where();
+        if let Some(preds) = where_clause.strip_prefix("where ") {
+            field(&pad, "where", preds, out);
+        }
+    }
+    if sym.mutable {
+        bool_field(&pad, "mutable", out);
+    }
+    if sym.is_unsafe {
+        bool_field(&pad, "unsafe", out);
+    }
+    if let Some(p) = parent {
+        field(&pad, "parent", p, out);
+    }
+    out.push_str(&pad);
+    out.push_str("  \"span\": ");
+    write_span(sym, lines, out);
+    out.push_str(",\n");
+    out.push_str(&pad);
+    out.push_str("  \"children\": ");
+    write_nodes(&sym.children, Some(&path), lines, depth + 1, out);
+    out.push('\n');
+    indent(depth, out);
+    out.push('}');
+}
+
+/// Write the `{ "start_byte": .., "end_byte": .., "start_line": .., "end_line": .. }`
+/// span object.
+fn write_span(sym: &Symbol, lines: &LineMap, out: &mut String) {
+    out.push_str(&format!(
+        "{{ \"start_byte\": {}, \"end_byte\": {}, \"start_line\": {}, \"end_line\": {} }}",
+        sym.start,
+        sym.end,
+        lines.line_of(sym.start),
+        lines.line_of(sym.end.saturating_sub(1)),
+    ));
+}
+
+/// Write a `"key": "value",` string field with the value escaped.
+fn field(pad: &str, key: &str, value: &str, out: &mut String) {
+    out.push_str(pad);
+    out.push_str("  \"");
+    out.push_str(key);
+    out.push_str("\": \"");
+    escape_into(value, out);
+    out.push_str("\",\n");
+}
+
+/// Write a `"key": true,` boolean field. Only emitted for the true case, so the
+/// value is always `true`.
+fn bool_field(pad: &str, key: &str, out: &mut String) {
+    out.push_str(pad);
+    out.push_str("  \"");
+    out.push_str(key);
+    out.push_str("\": true,\n");
+}
+
+/// Write a `"key": ["a", "b"],` array of escaped strings.
+fn string_array(pad: &str, key: &str, values: &[String], out: &mut String) {
+    out.push_str(pad);
+    out.push_str("  \"");
+    out.push_str(key);
+    out.push_str("\": [");
+    for (i, v) in values.iter().enumerate() {
+        out.push('"');
+        escape_into(v, out);
+        out.push('"');
+        if i + 1 < values.len() {
+            out.push_str(", ");
+        }
+    }
+    out.push_str("],\n");
+}
+
+fn indent(depth: usize, out: &mut String) {
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+/// Append `s` to `out` with the characters that are illegal in a JSON string
+/// escaped.
+fn escape_into(s: &str, out: &mut String) {
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Maps a byte offset to a 1-based line number using the source's line starts.
+struct LineMap {
+    /// Byte offset of the start of each line.
+    starts: Vec<usize>,
+}
+
+impl LineMap {
+    fn new(src: &str) -> Self {
+        let mut starts = vec![0];
+        for (i, b) in src.bytes().enumerate() {
+            if b == b'\n' {
+                starts.push(i + 1);
+            }
+        }
+        LineMap { starts }
+    }
+
+    /// The 1-based line number containing byte offset `pos`.
+    fn line_of(&self, pos: usize) -> usize {
+        match self.starts.binary_search(&pos) {
+            Ok(i) => i + 1,
+            Err(i) => i,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse;
+
+    use super::*;
+
+    #[test]
+    fn export_carries_nesting_spans_and_visibility() {
+        let src = "pub struct P {\n    x: i32,\n}";
+        let json = to_json(&parse(src), src);
+        assert!(json.contains("\"kind\": \"struct\""));
+        assert!(json.contains("\"name\": \"P\""));
+        assert!(json.contains("\"path\": \"P::x\""));
+        assert!(json.contains("\"visibility\": \"pub\""));
+        assert!(json.contains("\"start_line\": 1"));
+        // The field nests inside the struct's `children` array.
+        assert!(json.contains("\"children\": [\n"));
+    }
+
+    #[test]
+    fn export_surfaces_generics_mutable_and_unsafe() {
+        let src = "pub static mut COUNTER: u32 = 0;\n\
+                   pub unsafe fn danger() {}\n\
+                   fn g<T: Clone>(t: T) where T: Send { }";
+        let json = to_json(&parse(src), src);
+        // static mut → both flags reach the consumer.
+        assert!(json.contains("\"mutable\": true"));
+        assert!(json.contains("\"unsafe\": true"));
+        // generics and the where clause are emitted, not dropped.
+        assert!(json.contains("\"generics\": [\"T: Clone\"]"));
+        assert!(json.contains("\"where\": \"T: Send\""));
+    }
+
+    #[test]
+    fn export_distinguishes_concrete_impl_via_type_args() {
+        let src = "impl P<i32, i32> { fn f(&self) {} }";
+        let json = to_json(&parse(src), src);
+        assert!(json.contains("\"type_args\": [\"i32\", \"i32\"]"));
+    }
+}
@@ -0,0 +1,220 @@
+//! A small, forgiving tokenizer for Rust source.
+//!
+//! The parser only needs to recognise item headers and track bracket nesting,
+//! so the lexer does not attempt to be a faithful `rustc` lexer. It splits the
+//! input into identifiers, lifetimes, literals, and punctuation, preserving the
+//! byte span of every token so downstream symbols can report exact locations.
+
+/// The lexical category of a [`Token`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// An identifier or keyword (`fn`, `Point1`, `where`, ...).
+    Ident,
+    /// A lifetime token, including the leading tick (`'static`, `'a`).
+    Lifetime,
+    /// A char, byte, string, or numeric literal.
+    Literal,
+    /// Any run of punctuation treated as a single token (`->`, `::`, `{`).
+    Punct,
+}
+
+/// A single token with its source text and byte span.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    /// Byte offset of the first character, inclusive.
+    pub start: usize,
+    /// Byte offset one past the last character, exclusive.
+    pub end: usize,
+}
+
+/// Punctuation sequences recognised as a single token, longest first.
+///
+/// Note that `<<`/`>>` are deliberately *not* merged: keeping every angle
+/// bracket a single-character token lets the parser match nested generics like
+/// `Vec<Box<T>>` without having to split a merged `>>` back apart.
+const MULTI_PUNCT: &[&str] = &[
+    "..=", "...", "->", "=>", "::", "==", "!=", "<=", ">=", "&&", "||", "+=",
+    "-=", "*=", "/=", "%=", "^=", "&=", "|=", "..",
+];
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_alphabetic()
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
+}
+
+/// Tokenize `src`, discarding whitespace and comments.
+pub fn tokenize(src: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    tokenize_into(src, &mut tokens);
+    tokens
+}
+
+/// Tokenize `src` into `tokens`, clearing it first. Lets a caller reuse a
+/// single buffer across many files instead of allocating a fresh `Vec` each
+/// time.
+pub fn tokenize_into(src: &str, tokens: &mut Vec<Token>) {
+    tokens.clear();
+    let bytes = src.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &src[i..];
+        let c = bytes[i] as char;
+
+        // Whitespace.
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        // Line comment.
+        if rest.starts_with("//") {
+            i += rest.find('\n').map_or(rest.len(), |n| n);
+            continue;
+        }
+
+        // Block comment (nested).
+        if rest.starts_with("/*") {
+            let mut depth = 0usize;
+            let mut j = i;
+            while j < bytes.len() {
+                if src[j..].starts_with("/*") {
+                    depth += 1;
+                    j += 2;
+                } else if src[j..].starts_with("*/") {
+                    depth -= 1;
+                    j += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    j += 1;
+                }
+            }
+            i = j;
+            continue;
+        }
+
+        // String / char / lifetime starting with a tick.
+        if c == '"' || (c == 'b' && rest.starts_with("b\"")) {
+            let quote_off = if c == 'b' { 1 } else { 0 };
+            let end = scan_string(src, i + quote_off);
+            tokens.push(make(TokenKind::Literal, src, i, end));
+            i = end;
+            continue;
+        }
+        if c == '\'' {
+            let (end, kind) = scan_tick(src, i);
+            tokens.push(make(kind, src, i, end));
+            i = end;
+            continue;
+        }
+
+        // Identifier or keyword.
+        if is_ident_start(c) {
+            let mut j = i + c.len_utf8();
+            while j < bytes.len() {
+                let ch = src[j..].chars().next().unwrap();
+                if is_ident_continue(ch) {
+                    j += ch.len_utf8();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(make(TokenKind::Ident, src, i, j));
+            i = j;
+            continue;
+        }
+
+        // Numeric literal.
+        if c.is_ascii_digit() {
+            let mut j = i + 1;
+            while j < bytes.len() {
+                let ch = bytes[j] as char;
+                // A `.` is part of a float, but `..` starts a range and must
+                // not be swallowed into the literal.
+                if ch == '.' {
+                    if bytes.get(j + 1).map(|b| *b as char) == Some('.') {
+                        break;
+                    }
+                    j += 1;
+                } else if ch.is_ascii_alphanumeric() || ch == '_' {
+                    j += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push(make(TokenKind::Literal, src, i, j));
+            i = j;
+            continue;
+        }
+
+        // Punctuation.
+        if let Some(p) = MULTI_PUNCT.iter().find(|p| rest.starts_with(**p)) {
+            let end = i + p.len();
+            tokens.push(make(TokenKind::Punct, src, i, end));
+            i = end;
+            continue;
+        }
+        let end = i + c.len_utf8();
+        tokens.push(make(TokenKind::Punct, src, i, end));
+        i = end;
+    }
+}
+
+fn make(kind: TokenKind, src: &str, start: usize, end: usize) -> Token {
+    Token {
+        kind,
+        text: src[start..end].to_string(),
+        start,
+        end,
+    }
+}
+
+/// Scan a double-quoted string beginning at the opening quote, returning the
+/// byte offset one past the closing quote.
+fn scan_string(src: &str, open: usize) -> usize {
+    let bytes = src.as_bytes();
+    let mut j = open + 1;
+    while j < bytes.len() {
+        match bytes[j] as char {
+            '\\' => j += 2,
+            '"' => return j + 1,
+            _ => j += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Scan a token starting with `'`: either a lifetime (`'a`) or a char literal
+/// (`'x'`, `'\n'`). Returns the end offset and the resolved kind.
+fn scan_tick(src: &str, open: usize) -> (usize, TokenKind) {
+    let bytes = src.as_bytes();
+    // Escaped char literal: '\n', '\'', '\u{1F}'. Scan to the unescaped
+    // closing quote, honouring backslash escapes so `'\''` is not cut short
+    // and an unterminated literal at EOF stops at the end of input.
+    if open + 1 < bytes.len() && bytes[open + 1] as char == '\\' {
+        let mut j = open + 1;
+        while j < bytes.len() {
+            match bytes[j] as char {
+                '\\' => j += 2,
+                '\'' => return (j + 1, TokenKind::Literal),
+                _ => j += 1,
+            }
+        }
+        return (bytes.len(), TokenKind::Literal);
+    }
+    if open + 2 < bytes.len() && bytes[open + 2] as char == '\'' {
+        return (open + 3, TokenKind::Literal);
+    }
+    // Lifetime.
+    let mut j = open + 1;
+    while j < bytes.len() && is_ident_continue(bytes[j] as char) {
+        j += 1;
+    }
+    (j, TokenKind::Lifetime)
+}
@@ -0,0 +1,20 @@
+//! `rsym` extracts a structured symbol table from Rust source files.
+//!
+//! The entry point is [`parser::parse`], which turns source text into a list of
+//! [`symbol::Symbol`] records describing the structs, enums, traits, impls, and
+//! functions it finds, along with their generic machinery.
+
+pub mod audit;
+pub mod index;
+pub mod json;
+pub mod lexer;
+pub mod parser;
+pub mod pool;
+pub mod symbol;
+
+pub use audit::{audit, ErrorBehavior, FunctionAudit, PanicKind, PanicSite};
+pub use index::{ImplBlock, TraitIndex};
+pub use json::to_json;
+pub use parser::{parse, parse_batch};
+pub use pool::ObjectPool;
+pub use symbol::{GenericParam, Generics, Symbol, SymbolKind, Visibility, WherePredicate};
@@ -0,0 +1,124 @@
+//! Command-line front end: `rsym [--index | --json | --audit] <file.rs>`
+//! prints the symbols it finds, the trait-implementation index with `--index`,
+//! a machine-readable JSON symbol table with `--json`, or an error-handling
+//! audit with `--audit`.
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let mut index_mode = false;
+    let mut json_mode = false;
+    let mut audit_mode = false;
+    let mut path = None;
+    for arg in args.by_ref() {
+        match arg.as_str() {
+            "--index" => index_mode = true,
+            "--json" => json_mode = true,
+            "--audit" => audit_mode = true,
+            _ => path = Some(arg),
+        }
+    }
+    let Some(path) = path else {
+        eprintln!("usage: rsym [--index | --json | --audit] <file.rs>");
+        return ExitCode::FAILURE;
+    };
+    let src = match std::fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("rsym: cannot read {path}: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if audit_mode {
+        print_audit(&src);
+        return ExitCode::SUCCESS;
+    }
+    let symbols = rsym::parse(&src);
+    if json_mode {
+        println!("{}", rsym::to_json(&symbols, &src));
+    } else if index_mode {
+        print_index(&symbols);
+    } else {
+        for sym in &symbols {
+            print_symbol(sym, 0);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn print_audit(src: &str) {
+    for func in rsym::audit(src) {
+        let mut line = format!("{:<11} {}", func.behavior.label(), func.name);
+        if func.returns_result {
+            line.push_str(" -> Result");
+        } else if func.returns_option {
+            line.push_str(" -> Option");
+        }
+        println!("{line}");
+        for site in &func.panics {
+            println!("    {} @ {}..{}", site.kind.label(), site.start, site.end);
+        }
+    }
+}
+
+fn print_index(symbols: &[rsym::Symbol]) {
+    let index = rsym::TraitIndex::build(symbols);
+    for trait_name in index.traits() {
+        let types = index.implementors_of(trait_name);
+        println!("trait {trait_name} <- {}", types.join(", "));
+    }
+    for block in index.blocks() {
+        let header = match &block.trait_name {
+            Some(tr) => format!("impl {tr} for {}", block.type_name),
+            None => format!("impl {} (inherent)", block.type_name),
+        };
+        println!("{header}: {}", block.methods.join(", "));
+    }
+}
+
+fn print_symbol(sym: &rsym::Symbol, indent: usize) {
+    let mut line = format!(
+        "{:indent$}{:<11} {}",
+        "",
+        sym.kind.label(),
+        sym.name,
+        indent = indent
+    );
+    if !sym.type_args.is_empty() {
+        line.push_str(&format!("<{}>", sym.type_args.join(", ")));
+    }
+    if let Some(tr) = &sym.trait_name {
+        line.push_str(&format!(" : {tr}"));
+    }
+    if !sym.generics.params.is_empty() {
+        let params: Vec<_> = sym
+            .generics
+            .params
+            .iter()
+            .map(|p| p.render())
+            .collect();
+        line.push_str(&format!(" <{}>", params.join(", ")));
+    }
+    let where_clause = sym.generics.render_where();
+    if !where_clause.is_empty() {
+        line.push_str(&format!(" {where_clause}"));
+    }
+    if let Some(ty) = &sym.ty {
+        line.push_str(&format!(": {ty}"));
+    }
+    if let Some(value) = &sym.value {
+        line.push_str(&format!(" = {value}"));
+    }
+    if sym.mutable {
+        line.push_str("  [mut]");
+    }
+    if sym.is_unsafe {
+        line.push_str("  [unsafe]");
+    }
+    println!("{line}");
+    for child in &sym.children {
+        print_symbol(child, indent + 2);
+    }
+}
@@ -0,0 +1,1126 @@
+//! The symbol parser.
+//!
+//! This is a shallow, structure-only parser: it walks the token stream, finds
+//! item headers (`fn`, `struct`, `enum`, `trait`, `impl`, `type`), and records
+//! a [`Symbol`] for each, descending into `trait` and `impl` bodies to attribute
+//! their methods. It does not type-check or resolve paths.
+
+use crate::lexer::{tokenize_into, Token, TokenKind};
+use crate::pool::ObjectPool;
+use crate::symbol::{GenericParam, Generics, Symbol, SymbolKind, Visibility, WherePredicate};
+
+/// Parse `src` and return the discovered symbols in source order.
+pub fn parse(src: &str) -> Vec<Symbol> {
+    let mut tokens = Vec::new();
+    tokenize_into(src, &mut tokens);
+    Parser { src, tokens: &tokens }.run()
+}
+
+/// Parse many sources, reusing the transient token buffer across files via an
+/// [`ObjectPool`] so a large batch does not allocate and drop a fresh buffer
+/// for every file. Returns one symbol list per source, in order.
+pub fn parse_batch<'s>(sources: impl IntoIterator<Item = &'s str>) -> Vec<Vec<Symbol>> {
+    let mut pool: ObjectPool<Vec<Token>> = ObjectPool::new(Vec::new, 1, 8);
+    let mut results = Vec::new();
+    for src in sources {
+        let mut buf = pool.acquire().unwrap_or_default();
+        tokenize_into(src, &mut buf);
+        results.push(Parser { src, tokens: &buf }.run());
+        pool.release(buf);
+    }
+    results
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    tokens: &'a [Token],
+}
+
+impl<'a> Parser<'a> {
+    /// Walk the whole token stream and return the top-level symbols.
+    fn run(&self) -> Vec<Symbol> {
+        let mut out = Vec::new();
+        self.items(0, self.tokens.len(), false, &mut out);
+        out
+    }
+
+    /// Scan the tokens in `[lo, hi)` at brace depth zero for item headers.
+    /// `in_body` is true when the range is the body of a `trait`/`impl`, in
+    /// which case a `fn` is recorded as a [`SymbolKind::Method`].
+    fn items(&self, lo: usize, hi: usize, in_body: bool, out: &mut Vec<Symbol>) {
+        let mut i = lo;
+        while i < hi {
+            if let Some((vis, kw, start)) = self.item_head(i) {
+                i = match self.tokens[kw].text.as_str() {
+                    "fn" => self.parse_fn(kw, in_body, vis, start, out),
+                    "struct" | "enum" => self.parse_type_decl(kw, vis, start, out),
+                    "trait" => self.parse_trait(kw, vis, start, out),
+                    "impl" => self.parse_impl(kw, vis, start, out),
+                    "type" => self.parse_type_item(kw, in_body, vis, start, out),
+                    "const" => {
+                        let kind = if in_body {
+                            SymbolKind::AssocConst
+                        } else {
+                            SymbolKind::Const
+                        };
+                        self.parse_const(kw, kind, vis, start, out)
+                    }
+                    "static" => self.parse_static(kw, vis, start, out),
+                    _ => i + 1,
+                };
+                continue;
+            }
+            // Skip over any nested braces so their contents are not mistaken
+            // for items (e.g. a `struct` literal inside a function body).
+            if self.tokens[i].text == "{" {
+                i = self.matching(i);
+                continue;
+            }
+            i += 1;
+        }
+    }
+
+    fn parse_fn(
+        &self,
+        kw: usize,
+        in_body: bool,
+        vis: Visibility,
+        item_start: usize,
+        out: &mut Vec<Symbol>,
+    ) -> usize {
+        let start = self.tokens[item_start].start;
+        let name_idx = kw + 1;
+        let name = self.ident_text(name_idx);
+        let mut cur = name_idx + 1;
+
+        let mut generics = Generics::default();
+        if self.is_punct(cur, "<") {
+            let end = self.angle_end(cur);
+            generics.params = self.parse_generic_params(cur + 1, end - 1);
+            cur = end;
+        }
+        // Skip the argument list and return type up to `where`, `{`, or `;`.
+        let (where_lo, body) = self.scan_to_body(cur);
+        if let Some(w) = where_lo {
+            generics.where_clause = self.parse_where(w + 1, body);
+        }
+        let end = self.item_end(body);
+        let kind = if in_body {
+            SymbolKind::Method
+        } else {
+            SymbolKind::Function
+        };
+        let is_unsafe = self.is_unsafe_fn(kw) || self.body_has_unsafe(body);
+        out.push(Symbol {
+            kind,
+            name,
+            generics,
+            is_unsafe,
+            visibility: vis.clone(),
+            start,
+            end,
+            ..Default::default()
+        });
+        self.after(body)
+    }
+
+    /// Whether the `fn` at `kw` is declared `unsafe`, scanning the short run of
+    /// modifiers (`pub`, `const`, `async`, `extern "C"`) that may precede it.
+    fn is_unsafe_fn(&self, kw: usize) -> bool {
+        let mut i = kw;
+        while i > 0 {
+            i -= 1;
+            match self.tokens[i].text.as_str() {
+                "unsafe" => return true,
+                "pub" | "const" | "async" | "extern" | "default" => continue,
+                // A string (the ABI of `extern "C"`) or `)` of a visibility
+                // like `pub(crate)` is also skippable.
+                _ if self.tokens[i].kind == TokenKind::Literal => continue,
+                ")" => {
+                    i = self.open_of(i);
+                }
+                _ => return false,
+            }
+        }
+        false
+    }
+
+    /// Whether the body block starting at `body` contains an `unsafe { ... }`
+    /// block of its own, ignoring `unsafe` that belongs to a nested item (a
+    /// `fn`/`mod` declared inside the body).
+    fn body_has_unsafe(&self, body: usize) -> bool {
+        if !self.is_punct(body, "{") {
+            return false;
+        }
+        let close = self.matching(body);
+        let mut i = body + 1;
+        while i < close {
+            let t = &self.tokens[i];
+            if t.kind == TokenKind::Ident
+                && matches!(t.text.as_str(), "fn" | "mod" | "impl" | "trait")
+            {
+                // Skip the nested item so its `unsafe` does not count here.
+                let mut j = i + 1;
+                while j < close && self.tokens[j].text != "{" && self.tokens[j].text != ";" {
+                    j += 1;
+                }
+                i = if j < close && self.tokens[j].text == "{" {
+                    self.matching(j)
+                } else {
+                    j + 1
+                };
+                continue;
+            }
+            if t.kind == TokenKind::Ident && t.text == "unsafe" && self.is_punct(i + 1, "{") {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// If an item begins at token `i`, return its visibility, the index of its
+    /// item keyword, and the index of its first token (the visibility or first
+    /// modifier, for span purposes). Returns `None` when `i` is not the start
+    /// of an item.
+    fn item_head(&self, i: usize) -> Option<(Visibility, usize, usize)> {
+        if self.tokens.get(i).map(|t| t.kind) != Some(TokenKind::Ident) {
+            return None;
+        }
+        let start = i;
+        let mut j = i;
+        let mut vis = Visibility::Private;
+        if self.tokens[j].text == "pub" {
+            let (v, next) = self.read_vis(j);
+            vis = v;
+            j = next;
+        }
+        // Skip leading modifiers that precede the item keyword.
+        loop {
+            match self.tokens.get(j).map(|t| t.text.as_str()) {
+                Some("unsafe") | Some("async") | Some("default") => j += 1,
+                Some("extern") => {
+                    j += 1;
+                    if self.tokens.get(j).map(|t| t.kind) == Some(TokenKind::Literal) {
+                        j += 1; // the ABI string, e.g. "C"
+                    }
+                }
+                Some("const") if self.is_const_fn(j) => j += 1,
+                _ => break,
+            }
+        }
+        match self.tokens.get(j) {
+            Some(t)
+                if t.kind == TokenKind::Ident
+                    && matches!(
+                        t.text.as_str(),
+                        "fn" | "struct" | "enum" | "trait" | "impl" | "type" | "const" | "static"
+                    ) =>
+            {
+                Some((vis, j, start))
+            }
+            _ => None,
+        }
+    }
+
+    /// Read a `pub` visibility starting at `j`, returning it and the index of
+    /// the token after the marker.
+    fn read_vis(&self, j: usize) -> (Visibility, usize) {
+        if self.is_punct(j + 1, "(") {
+            let close = self.matching(j + 1);
+            let inner = self.text(j + 2, close - 1).trim().to_string();
+            let vis = if inner == "crate" {
+                Visibility::Crate
+            } else {
+                Visibility::Restricted(inner)
+            };
+            (vis, close)
+        } else {
+            (Visibility::Public, j + 1)
+        }
+    }
+
+    /// Whether the `const` at `kw` is the leading modifier of a `const fn`
+    /// (possibly `const unsafe fn` / `const extern "C" fn`) rather than a
+    /// `const` item.
+    fn is_const_fn(&self, kw: usize) -> bool {
+        let mut j = kw + 1;
+        loop {
+            match self.tokens.get(j) {
+                Some(t) if t.kind == TokenKind::Ident && t.text == "fn" => return true,
+                Some(t) if t.kind == TokenKind::Ident && (t.text == "unsafe" || t.text == "extern") => {
+                    j += 1
+                }
+                Some(t) if t.kind == TokenKind::Literal => j += 1, // extern "C"
+                _ => return false,
+            }
+        }
+    }
+
+    /// Given the index of a closing `)`, `]`, or `}`, return the index of its
+    /// matching opener (scanning backwards).
+    fn open_of(&self, close: usize) -> usize {
+        let (o, c) = match self.tokens[close].text.as_str() {
+            ")" => ("(", ")"),
+            "]" => ("[", "]"),
+            "}" => ("{", "}"),
+            _ => return close,
+        };
+        let mut depth = 0i32;
+        let mut i = close;
+        loop {
+            let t = self.tokens[i].text.as_str();
+            if t == c {
+                depth += 1;
+            } else if t == o {
+                depth -= 1;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            if i == 0 {
+                return 0;
+            }
+            i -= 1;
+        }
+    }
+
+    fn parse_type_decl(
+        &self,
+        kw: usize,
+        vis: Visibility,
+        item_start: usize,
+        out: &mut Vec<Symbol>,
+    ) -> usize {
+        let start = self.tokens[item_start].start;
+        let kind = if self.tokens[kw].text == "struct" {
+            SymbolKind::Struct
+        } else {
+            SymbolKind::Enum
+        };
+        let name = self.ident_text(kw + 1);
+        let mut cur = kw + 2;
+        let mut generics = Generics::default();
+        if self.is_punct(cur, "<") {
+            let end = self.angle_end(cur);
+            generics.params = self.parse_generic_params(cur + 1, end - 1);
+            cur = end;
+        }
+        let (where_lo, body) = self.scan_to_body(cur);
+        if let Some(w) = where_lo {
+            generics.where_clause = self.parse_where(w + 1, body);
+        }
+        let end = self.item_end(body);
+        let children = if self.is_punct(body, "{") {
+            match kind {
+                SymbolKind::Struct => self.parse_fields(body),
+                _ => self.parse_variants(body, &vis),
+            }
+        } else {
+            Vec::new()
+        };
+        out.push(Symbol {
+            kind,
+            name,
+            generics,
+            children,
+            visibility: vis.clone(),
+            start,
+            end,
+            ..Default::default()
+        });
+        self.after(body)
+    }
+
+    /// Parse the named fields of a braced struct body, returning one
+    /// [`SymbolKind::Field`] per field with its visibility and declared type.
+    fn parse_fields(&self, body: usize) -> Vec<Symbol> {
+        let close = self.matching(body);
+        let mut fields = Vec::new();
+        for (a, b) in self.split_commas(body + 1, close - 1) {
+            if a >= b {
+                continue;
+            }
+            let (vis, name_idx) = if self.tokens[a].text == "pub" {
+                self.read_vis(a)
+            } else {
+                (Visibility::Private, a)
+            };
+            let name = self.ident_text(name_idx);
+            if name.is_empty() {
+                continue;
+            }
+            let ty = self.text_after_colon(name_idx, b);
+            fields.push(Symbol {
+                kind: SymbolKind::Field,
+                name,
+                visibility: vis,
+                ty: (!ty.is_empty()).then_some(ty),
+                start: self.tokens[a].start,
+                end: self.tokens[b - 1].end,
+                ..Default::default()
+            });
+        }
+        fields
+    }
+
+    /// Parse the variants of a braced enum body, returning one
+    /// [`SymbolKind::Variant`] per variant along with its explicit
+    /// discriminant (`= 1`) when present. Variants have no visibility of their
+    /// own — they are as accessible as the enum — so each inherits `vis`.
+    ///
+    /// Splitting tracks only `()`/`[]`/`{}` nesting, which already protects the
+    /// commas of tuple and struct-like variants; angle nesting is deliberately
+    /// ignored so a discriminant expression using `<<`/`>>` (common in flag
+    /// enums) does not swallow the variants that follow it.
+    fn parse_variants(&self, body: usize, vis: &Visibility) -> Vec<Symbol> {
+        let close = self.matching(body);
+        let mut variants = Vec::new();
+        for (a, b) in self.split_brackets(body + 1, close - 1, ",") {
+            if a >= b || self.tokens[a].kind != TokenKind::Ident {
+                continue;
+            }
+            let name = self.ident_text(a);
+            let value = self.text_after_eq(a, b);
+            variants.push(Symbol {
+                kind: SymbolKind::Variant,
+                name,
+                value,
+                visibility: vis.clone(),
+                start: self.tokens[a].start,
+                end: self.tokens[b - 1].end,
+                ..Default::default()
+            });
+        }
+        variants
+    }
+
+    fn parse_trait(
+        &self,
+        kw: usize,
+        vis: Visibility,
+        item_start: usize,
+        out: &mut Vec<Symbol>,
+    ) -> usize {
+        let start = self.tokens[item_start].start;
+        let name = self.ident_text(kw + 1);
+        let mut cur = kw + 2;
+        let mut generics = Generics::default();
+        if self.is_punct(cur, "<") {
+            let end = self.angle_end(cur);
+            generics.params = self.parse_generic_params(cur + 1, end - 1);
+            cur = end;
+        }
+        let (where_lo, body) = self.scan_to_body(cur);
+        if let Some(w) = where_lo {
+            generics.where_clause = self.parse_where(w + 1, body);
+        }
+        let end = self.item_end(body);
+        let children = self.parse_body(body);
+        out.push(Symbol {
+            kind: SymbolKind::Trait,
+            name,
+            generics,
+            children,
+            visibility: vis.clone(),
+            start,
+            end,
+            ..Default::default()
+        });
+        self.after(body)
+    }
+
+    fn parse_impl(
+        &self,
+        kw: usize,
+        vis: Visibility,
+        item_start: usize,
+        out: &mut Vec<Symbol>,
+    ) -> usize {
+        let start = self.tokens[item_start].start;
+        let mut cur = kw + 1;
+        let mut generics = Generics::default();
+        if self.is_punct(cur, "<") {
+            let end = self.angle_end(cur);
+            generics.params = self.parse_generic_params(cur + 1, end - 1);
+            cur = end;
+        }
+        // After the generic params comes either `Trait for Type` or just `Type`.
+        let (where_lo, body) = self.scan_to_body(cur);
+        let header_end = where_lo.unwrap_or(body);
+        let (trait_name, type_name, type_args) = self.parse_impl_header(cur, header_end);
+        if let Some(w) = where_lo {
+            generics.where_clause = self.parse_where(w + 1, body);
+        }
+        let end = self.item_end(body);
+        let children = self.parse_body(body);
+        out.push(Symbol {
+            kind: SymbolKind::Impl,
+            name: type_name,
+            generics,
+            trait_name,
+            type_args,
+            children,
+            visibility: vis.clone(),
+            start,
+            end,
+            ..Default::default()
+        });
+        self.after(body)
+    }
+
+    /// Parse the body `{ ... }` whose opening brace is at `body`, returning its
+    /// items (methods, associated types, and associated consts) to nest under
+    /// the parent trait or impl.
+    fn parse_body(&self, body: usize) -> Vec<Symbol> {
+        let mut children = Vec::new();
+        if self.is_punct(body, "{") {
+            self.items(body + 1, self.matching(body), true, &mut children);
+        }
+        children
+    }
+
+    /// Parse a `type` item: a module-level alias, or — inside a trait/impl
+    /// body — an associated type, recording any bound and binding.
+    fn parse_type_item(
+        &self,
+        kw: usize,
+        in_body: bool,
+        vis: Visibility,
+        item_start: usize,
+        out: &mut Vec<Symbol>,
+    ) -> usize {
+        let start = self.tokens[item_start].start;
+        let name = self.ident_text(kw + 1);
+        let mut cur = kw + 2;
+        let mut generics = Generics::default();
+        if self.is_punct(cur, "<") {
+            let end = self.angle_end(cur);
+            generics.params = self.parse_generic_params(cur + 1, end - 1);
+            cur = end;
+        }
+        // A `type` item ends at its `;`; its right-hand side may itself contain
+        // braces (`= Foo { .. }`), so scan for the terminator rather than the
+        // first block.
+        let body = self.scan_to_semi(cur);
+        let end = self.item_end(body);
+        if in_body {
+            // `type Item<..>: Bound = Binding;` — the bound (if any) and the
+            // binding (if any) sit before any trailing `where` clause.
+            let limit = self.top_level_where(cur, body);
+            let bound = self.text_after_colon_before_eq(cur, limit);
+            let value = self.text_after_eq(cur, limit);
+            out.push(Symbol {
+                kind: SymbolKind::AssocType,
+                name,
+                generics,
+                visibility: vis,
+                ty: bound,
+                value,
+                start,
+                end,
+                ..Default::default()
+            });
+        } else {
+            out.push(Symbol {
+                kind: SymbolKind::TypeAlias,
+                name,
+                generics,
+                visibility: vis,
+                start,
+                end,
+                ..Default::default()
+            });
+        }
+        self.after(body)
+    }
+
+    /// Parse a `const NAME: TYPE [= VALUE];`, as either a module-level
+    /// [`SymbolKind::Const`] or an [`SymbolKind::AssocConst`] inside a
+    /// trait/impl, recording its declared type and initializer.
+    fn parse_const(
+        &self,
+        kw: usize,
+        kind: SymbolKind,
+        vis: Visibility,
+        item_start: usize,
+        out: &mut Vec<Symbol>,
+    ) -> usize {
+        let start = self.tokens[item_start].start;
+        let name = self.ident_text(kw + 1);
+        let cur = kw + 2;
+        let body = self.scan_to_semi(cur);
+        let end = self.item_end(body);
+        let limit = self.top_level_where(cur, body);
+        let ty = self.text_after_colon_before_eq(cur, limit);
+        let value = self.text_after_eq(cur, limit);
+        out.push(Symbol {
+            kind,
+            name,
+            ty,
+            value,
+            visibility: vis.clone(),
+            start,
+            end,
+            ..Default::default()
+        });
+        self.after(body)
+    }
+
+    /// Parse a `static [mut] NAME: TYPE = VALUE;`, recording its mutability and
+    /// marking a `static mut` as unsafe to touch.
+    fn parse_static(
+        &self,
+        kw: usize,
+        vis: Visibility,
+        item_start: usize,
+        out: &mut Vec<Symbol>,
+    ) -> usize {
+        let start = self.tokens[item_start].start;
+        let mut name_idx = kw + 1;
+        let mutable = self.ident_text(name_idx) == "mut";
+        if mutable {
+            name_idx += 1;
+        }
+        let name = self.ident_text(name_idx);
+        let cur = name_idx + 1;
+        let body = self.scan_to_semi(cur);
+        let end = self.item_end(body);
+        let ty = self.text_after_colon_before_eq(cur, body);
+        let value = self.text_after_eq(cur, body);
+        out.push(Symbol {
+            kind: SymbolKind::Static,
+            name,
+            ty,
+            value,
+            mutable,
+            is_unsafe: mutable,
+            visibility: vis.clone(),
+            start,
+            end,
+            ..Default::default()
+        });
+        self.after(body)
+    }
+
+    // --- header helpers -------------------------------------------------
+
+    /// Parse an `impl` header in `[lo, hi)`, returning
+    /// `(trait_name, type_name, type_args)`.
+    fn parse_impl_header(&self, lo: usize, hi: usize) -> (Option<String>, String, Vec<String>) {
+        // Find a top-level `for` separating the trait from the type.
+        let mut for_idx = None;
+        let mut depth = 0i32;
+        let mut i = lo;
+        while i < hi {
+            depth += self.angle_delta(i);
+            if depth == 0 && self.tokens[i].kind == TokenKind::Ident && self.tokens[i].text == "for"
+            {
+                for_idx = Some(i);
+                break;
+            }
+            i += 1;
+        }
+        match for_idx {
+            Some(f) => {
+                let trait_name = self.type_base(lo, f);
+                let (type_name, args) = self.type_base_and_args(f + 1, hi);
+                (Some(trait_name), type_name, args)
+            }
+            None => {
+                let (type_name, args) = self.type_base_and_args(lo, hi);
+                (None, type_name, args)
+            }
+        }
+    }
+
+    /// The leading type path's final segment (ignoring generic args) in a range.
+    fn type_base(&self, lo: usize, hi: usize) -> String {
+        // Take the last identifier before a `<` or the end of the range.
+        let mut name = String::new();
+        let mut i = lo;
+        while i < hi {
+            if self.tokens[i].kind == TokenKind::Ident && self.tokens[i].text != "dyn" {
+                name = self.tokens[i].text.clone();
+            } else if self.is_punct(i, "<") {
+                break;
+            }
+            i += 1;
+        }
+        name
+    }
+
+    /// Like [`type_base`], but also collects the generic arguments applied at
+    /// the use site, e.g. `Point1<i32, i32>` -> (`Point1`, ["i32", "i32"]).
+    fn type_base_and_args(&self, lo: usize, hi: usize) -> (String, Vec<String>) {
+        let mut name = String::new();
+        let mut i = lo;
+        while i < hi {
+            if self.is_punct(i, "<") {
+                let end = self.angle_end(i).min(hi);
+                let args = self
+                    .split_commas(i + 1, end - 1)
+                    .into_iter()
+                    .map(|(a, b)| self.text(a, b).trim().to_string())
+                    .collect();
+                return (name, args);
+            }
+            if self.tokens[i].kind == TokenKind::Ident && self.tokens[i].text != "dyn" {
+                name = self.tokens[i].text.clone();
+            }
+            i += 1;
+        }
+        (name, Vec::new())
+    }
+
+    /// Parse a generic parameter list from the tokens in `[lo, hi)` (the
+    /// contents between the angle brackets).
+    fn parse_generic_params(&self, lo: usize, hi: usize) -> Vec<GenericParam> {
+        let mut params = Vec::new();
+        for (a, b) in self.split_commas(lo, hi) {
+            if a >= b {
+                continue;
+            }
+            let first = &self.tokens[a];
+            if first.kind == TokenKind::Lifetime {
+                let bounds = self.bounds_after_colon(a, b);
+                params.push(GenericParam::Lifetime {
+                    name: first.text.clone(),
+                    bounds,
+                });
+            } else if first.text == "const" {
+                let name = self.ident_text(a + 1);
+                let ty = self.text_after_colon(a, b);
+                params.push(GenericParam::Const { name, ty });
+            } else {
+                let name = first.text.clone();
+                let bounds = self.bounds_after_colon(a, b);
+                params.push(GenericParam::Type { name, bounds });
+            }
+        }
+        params
+    }
+
+    /// Parse a `where` clause from the tokens in `[lo, hi)`.
+    fn parse_where(&self, lo: usize, hi: usize) -> Vec<WherePredicate> {
+        let mut out = Vec::new();
+        for (a, b) in self.split_commas(lo, hi) {
+            if a >= b {
+                continue;
+            }
+            let target = self.text_before_colon(a, b);
+            let bounds = self.bounds_after_colon(a, b);
+            if !target.is_empty() {
+                out.push(WherePredicate { target, bounds });
+            }
+        }
+        out
+    }
+
+    /// Collect the `+`-separated bounds following the first top-level `:` in a
+    /// parameter or predicate range.
+    fn bounds_after_colon(&self, lo: usize, hi: usize) -> Vec<String> {
+        let Some(colon) = self.top_level_colon(lo, hi) else {
+            return Vec::new();
+        };
+        self.split_plus(colon + 1, hi)
+            .into_iter()
+            .map(|(a, b)| self.text(a, b).trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    fn text_before_colon(&self, lo: usize, hi: usize) -> String {
+        let end = self.top_level_colon(lo, hi).unwrap_or(hi);
+        self.text(lo, end).trim().to_string()
+    }
+
+    fn text_after_colon(&self, lo: usize, hi: usize) -> String {
+        match self.top_level_colon(lo, hi) {
+            Some(colon) => self.text(colon + 1, hi).trim().to_string(),
+            None => String::new(),
+        }
+    }
+
+    // --- token-stream utilities ----------------------------------------
+
+    /// Scan from `cur` to the item body, returning the index of a top-level
+    /// `where` keyword (if any) and the index of the `{` or `;` that ends the
+    /// header.
+    fn scan_to_body(&self, cur: usize) -> (Option<usize>, usize) {
+        let mut depth = 0i32;
+        let mut i = cur;
+        let mut where_lo = None;
+        while i < self.tokens.len() {
+            let t = &self.tokens[i];
+            match t.text.as_str() {
+                "(" | "[" => depth += 1,
+                ")" | "]" => depth -= 1,
+                "{" if depth == 0 => return (where_lo, i),
+                ";" if depth == 0 => return (where_lo, i),
+                "where" if depth == 0 && t.kind == TokenKind::Ident && where_lo.is_none() => {
+                    where_lo = Some(i)
+                }
+                _ => depth += self.angle_delta(i),
+            }
+            i += 1;
+        }
+        (where_lo, self.tokens.len().saturating_sub(1))
+    }
+
+    /// Scan from `cur` to the top-level `;` that ends a blockless item (a
+    /// `type`, `const`, or `static`), skipping balanced brackets so an
+    /// initializer like `= Foo { .. }` does not terminate the scan early.
+    fn scan_to_semi(&self, cur: usize) -> usize {
+        let mut depth = 0i32;
+        let mut i = cur;
+        while i < self.tokens.len() {
+            match self.tokens[i].text.as_str() {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth -= 1,
+                ";" if depth == 0 => return i,
+                _ => {}
+            }
+            i += 1;
+        }
+        self.tokens.len().saturating_sub(1)
+    }
+
+    /// The byte offset one past the item whose header ends at `body` (either a
+    /// `{` block or a `;`).
+    fn item_end(&self, body: usize) -> usize {
+        if body >= self.tokens.len() {
+            return self.src.len();
+        }
+        if self.tokens[body].text == "{" {
+            let close = self.matching(body);
+            self.tokens[close - 1].end
+        } else {
+            self.tokens[body].end
+        }
+    }
+
+    /// The token index at which scanning should resume after the item whose
+    /// header ends at `body`.
+    fn after(&self, body: usize) -> usize {
+        if body >= self.tokens.len() {
+            return self.tokens.len();
+        }
+        if self.tokens[body].text == "{" {
+            self.matching(body)
+        } else {
+            body + 1
+        }
+    }
+
+    /// Given the index of an opening bracket token, return the index one past
+    /// its matching close.
+    fn matching(&self, open: usize) -> usize {
+        let (o, c) = match self.tokens[open].text.as_str() {
+            "{" => ("{", "}"),
+            "(" => ("(", ")"),
+            "[" => ("[", "]"),
+            _ => return open + 1,
+        };
+        let mut depth = 0i32;
+        let mut i = open;
+        while i < self.tokens.len() {
+            let t = self.tokens[i].text.as_str();
+            if t == o {
+                depth += 1;
+            } else if t == c {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            i += 1;
+        }
+        self.tokens.len()
+    }
+
+    /// Return the index one past the `>` that closes the `<` at `open`,
+    /// accounting for `>>`-style tokens that close two levels at once.
+    fn angle_end(&self, open: usize) -> usize {
+        let mut depth = 0i32;
+        let mut i = open;
+        while i < self.tokens.len() {
+            depth += self.angle_delta(i);
+            if depth <= 0 && i > open {
+                return i + 1;
+            }
+            i += 1;
+        }
+        self.tokens.len()
+    }
+
+    /// The change in angle-bracket nesting contributed by token `i`.
+    fn angle_delta(&self, i: usize) -> i32 {
+        let t = &self.tokens[i];
+        if t.kind != TokenKind::Punct {
+            return 0;
+        }
+        if t.text.chars().all(|c| c == '<') {
+            t.text.len() as i32
+        } else if t.text.chars().all(|c| c == '>') {
+            -(t.text.len() as i32)
+        } else {
+            0
+        }
+    }
+
+    /// Split the tokens in `[lo, hi)` on top-level commas, returning the
+    /// `[start, end)` token range of each group.
+    fn split_commas(&self, lo: usize, hi: usize) -> Vec<(usize, usize)> {
+        self.split_on(lo, hi, ",")
+    }
+
+    /// Split on top-level `+` (for bound lists).
+    fn split_plus(&self, lo: usize, hi: usize) -> Vec<(usize, usize)> {
+        self.split_on(lo, hi, "+")
+    }
+
+    /// Like [`split_on`], but tracks only `()`/`[]`/`{}` nesting and ignores
+    /// angle brackets, so separators inside a shift expression (`1 << 0`) are
+    /// still seen. Used where the groups are not generic-argument lists.
+    fn split_brackets(&self, lo: usize, hi: usize, sep: &str) -> Vec<(usize, usize)> {
+        self.split_inner(lo, hi, sep, false)
+    }
+
+    fn split_on(&self, lo: usize, hi: usize, sep: &str) -> Vec<(usize, usize)> {
+        self.split_inner(lo, hi, sep, true)
+    }
+
+    /// Split the tokens in `[lo, hi)` on top-level occurrences of `sep`. When
+    /// `track_angle` is set, separators inside `<...>` are also skipped, so a
+    /// generic-argument comma does not split the list.
+    fn split_inner(&self, lo: usize, hi: usize, sep: &str, track_angle: bool) -> Vec<(usize, usize)> {
+        let mut groups = Vec::new();
+        let mut depth = 0i32;
+        let mut angle = 0i32;
+        let mut start = lo;
+        let mut i = lo;
+        while i < hi {
+            match self.tokens[i].text.as_str() {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth -= 1,
+                s if s == sep && depth == 0 && angle == 0 => {
+                    groups.push((start, i));
+                    start = i + 1;
+                }
+                _ if track_angle => angle += self.angle_delta(i),
+                _ => {}
+            }
+            i += 1;
+        }
+        if start < hi {
+            groups.push((start, hi));
+        }
+        groups
+    }
+
+    /// The index of the first top-level `:` (not `::`) in `[lo, hi)`.
+    fn top_level_colon(&self, lo: usize, hi: usize) -> Option<usize> {
+        let mut depth = 0i32;
+        let mut angle = 0i32;
+        for i in lo..hi {
+            match self.tokens[i].text.as_str() {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth -= 1,
+                ":" if depth == 0 && angle == 0 => return Some(i),
+                _ => angle += self.angle_delta(i),
+            }
+        }
+        None
+    }
+
+    /// The index of a top-level `where` keyword in `[lo, hi)`, or `hi` when the
+    /// item has no `where` clause.
+    fn top_level_where(&self, lo: usize, hi: usize) -> usize {
+        let mut depth = 0i32;
+        let mut angle = 0i32;
+        for i in lo..hi {
+            match self.tokens[i].text.as_str() {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth -= 1,
+                "where" if depth == 0 && angle == 0 && self.tokens[i].kind == TokenKind::Ident => {
+                    return i
+                }
+                _ => angle += self.angle_delta(i),
+            }
+        }
+        hi
+    }
+
+    /// The index of the first top-level `=` (not `==`, `=>`) in `[lo, hi)`.
+    fn top_level_eq(&self, lo: usize, hi: usize) -> Option<usize> {
+        let mut depth = 0i32;
+        let mut angle = 0i32;
+        for i in lo..hi {
+            match self.tokens[i].text.as_str() {
+                "(" | "[" | "{" => depth += 1,
+                ")" | "]" | "}" => depth -= 1,
+                "=" if depth == 0 && angle == 0 => return Some(i),
+                _ => angle += self.angle_delta(i),
+            }
+        }
+        None
+    }
+
+    /// The text between a top-level `:` and the following `=` (or `;`), i.e. the
+    /// declared type or bound of an associated item. `None` when absent.
+    fn text_after_colon_before_eq(&self, lo: usize, hi: usize) -> Option<String> {
+        let colon = self.top_level_colon(lo, hi)?;
+        let end = self.top_level_eq(colon + 1, hi).unwrap_or(hi);
+        let text = self.text(colon + 1, end).trim().to_string();
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// The text following a top-level `=`, i.e. the binding or initializer.
+    /// `None` when there is no `=`.
+    fn text_after_eq(&self, lo: usize, hi: usize) -> Option<String> {
+        let eq = self.top_level_eq(lo, hi)?;
+        let text = self.text(eq + 1, hi).trim().to_string();
+        (!text.is_empty()).then_some(text)
+    }
+
+    fn is_punct(&self, i: usize, s: &str) -> bool {
+        i < self.tokens.len()
+            && self.tokens[i].kind == TokenKind::Punct
+            && self.tokens[i].text == s
+    }
+
+    fn ident_text(&self, i: usize) -> String {
+        self.tokens
+            .get(i)
+            .map(|t| t.text.clone())
+            .unwrap_or_default()
+    }
+
+    /// The source text spanned by the tokens in `[lo, hi)`.
+    fn text(&self, lo: usize, hi: usize) -> &str {
+        if lo >= hi || lo >= self.tokens.len() {
+            return "";
+        }
+        let end = hi.min(self.tokens.len());
+        &self.src[self.tokens[lo].start..self.tokens[end - 1].end]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_param_bounds_lifetimes_and_where_clause() {
+        let src = "fn f<'a, T>(t: T) -> T where T: Clone { t }";
+        let syms = parse(src);
+        let f = &syms[0];
+        assert_eq!(f.generics.params.len(), 2);
+        assert_eq!(
+            f.generics.params[0],
+            GenericParam::Lifetime {
+                name: "'a".to_string(),
+                bounds: Vec::new(),
+            }
+        );
+        assert_eq!(
+            f.generics.params[1],
+            GenericParam::Type {
+                name: "T".to_string(),
+                bounds: Vec::new(),
+            }
+        );
+        assert_eq!(
+            f.generics.where_clause,
+            vec![WherePredicate {
+                target: "T".to_string(),
+                bounds: vec!["Clone".to_string()],
+            }]
+        );
+    }
+
+    fn named<'a>(syms: &'a [Symbol], name: &str) -> &'a Symbol {
+        syms.iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("no symbol named {name}"))
+    }
+
+    #[test]
+    fn static_mut_is_mutable_and_unsafe() {
+        let src = "static mut COUNTER: u32 = 0;\nstatic LIMIT: u32 = 9;";
+        let syms = parse(src);
+        let counter = named(&syms, "COUNTER");
+        assert_eq!(counter.kind, SymbolKind::Static);
+        assert!(counter.mutable);
+        assert!(counter.is_unsafe);
+        let limit = named(&syms, "LIMIT");
+        assert!(!limit.mutable);
+        assert!(!limit.is_unsafe);
+    }
+
+    #[test]
+    fn const_item_and_const_fn_are_distinguished() {
+        let src = "const MAX: u8 = 9;\nconst fn c() -> u8 { 0 }";
+        let syms = parse(src);
+        assert_eq!(named(&syms, "MAX").kind, SymbolKind::Const);
+        assert_eq!(named(&syms, "c").kind, SymbolKind::Function);
+    }
+
+    #[test]
+    fn unsafe_block_body_marks_function_unsafe() {
+        let src = "fn danger() { unsafe { } }\nfn safe() {}\nunsafe fn raw() {}";
+        let syms = parse(src);
+        assert!(named(&syms, "danger").is_unsafe);
+        assert!(!named(&syms, "safe").is_unsafe);
+        assert!(named(&syms, "raw").is_unsafe);
+    }
+
+    #[test]
+    fn associated_items_nest_under_trait_and_impl() {
+        let src = "trait Store { type Item; const ID: u32; }\n\
+                   impl Store for S { type Item = u8; const ID: u32 = 7; }";
+        let syms = parse(src);
+        let trait_sym = &syms[0];
+        assert_eq!(trait_sym.kind, SymbolKind::Trait);
+        assert_eq!(trait_sym.children[0].kind, SymbolKind::AssocType);
+        assert_eq!(trait_sym.children[0].name, "Item");
+        assert_eq!(trait_sym.children[0].value, None);
+        assert_eq!(trait_sym.children[1].kind, SymbolKind::AssocConst);
+        assert_eq!(trait_sym.children[1].ty.as_deref(), Some("u32"));
+
+        let impl_sym = &syms[1];
+        assert_eq!(impl_sym.kind, SymbolKind::Impl);
+        assert_eq!(impl_sym.children[0].value.as_deref(), Some("u8"));
+        assert_eq!(impl_sym.children[1].value.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn shift_discriminant_does_not_split_variants() {
+        // `1 << 0` must not be mistaken for an angle bracket when splitting the
+        // variant list (regression for c2155d8).
+        let src = "pub enum Flags { A = 1 << 0, B = 1 << 1 }";
+        let e = &parse(src)[0];
+        let variants: Vec<_> = e.children.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(variants, ["A", "B"]);
+        assert_eq!(e.children[0].value.as_deref(), Some("1 << 0"));
+        assert_eq!(e.children[1].value.as_deref(), Some("1 << 1"));
+        // A `pub enum`'s variants inherit its visibility.
+        assert_eq!(e.children[0].visibility, Visibility::Public);
+    }
+
+    #[test]
+    fn captures_higher_ranked_bound_with_arrow_and_lifetime() {
+        // The `+`-separated bound list must survive a `Fn() -> T` that itself
+        // contains a `->`, and pick up the trailing `'static`.
+        let src = "fn g<T: Fn() -> T + 'static>(t: T) {}";
+        let f = &parse(src)[0];
+        assert_eq!(
+            f.generics.params[0],
+            GenericParam::Type {
+                name: "T".to_string(),
+                bounds: vec!["Fn() -> T".to_string(), "'static".to_string()],
+            }
+        );
+    }
+}
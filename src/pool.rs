@@ -0,0 +1,90 @@
+//! A simple object pool with factory, `acquire`, and `release` semantics.
+//!
+//! The parser reuses transient node buffers through a pool to avoid allocating
+//! and dropping them once per file in large-batch runs.
+
+/// A pool of reusable `T` values, backed by a user-supplied factory.
+pub struct ObjectPool<T> {
+    available: Vec<T>,
+    factory: Box<dyn Fn() -> T>,
+    max_size: usize,
+    /// Objects handed out by `acquire` and not yet returned by `release`.
+    outstanding: usize,
+}
+
+impl<T> ObjectPool<T> {
+    /// Create a pool that pre-allocates `initial_size` objects and never lets
+    /// more than `max_size` of them exist at once (pooled plus outstanding).
+    pub fn new(factory: impl Fn() -> T + 'static, initial_size: usize, max_size: usize) -> Self {
+        let mut available = Vec::with_capacity(max_size);
+        for _ in 0..initial_size {
+            available.push(factory());
+        }
+        Self {
+            available,
+            factory: Box::new(factory),
+            max_size,
+            outstanding: 0,
+        }
+    }
+
+    /// Take an object from the pool, creating a fresh one via the factory when
+    /// the pool is empty. Returns `None` once `max_size` objects are already in
+    /// circulation (pooled plus outstanding).
+    pub fn acquire(&mut self) -> Option<T> {
+        let item = if let Some(item) = self.available.pop() {
+            item
+        } else if self.available.len() + self.outstanding < self.max_size {
+            (self.factory)()
+        } else {
+            return None;
+        };
+        self.outstanding += 1;
+        Some(item)
+    }
+
+    /// Return an object to the pool, dropping it if the pool is already full.
+    pub fn release(&mut self, item: T) {
+        self.outstanding = self.outstanding.saturating_sub(1);
+        if self.available.len() < self.max_size {
+            self.available.push(item);
+        }
+    }
+
+    /// The number of objects currently held in the pool.
+    pub fn size(&self) -> usize {
+        self.available.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acquire_caps_objects_in_circulation() {
+        let mut pool = ObjectPool::new(|| 0u32, 0, 2);
+        let a = pool.acquire();
+        let b = pool.acquire();
+        assert!(a.is_some() && b.is_some());
+        // Two objects are outstanding; the cap is reached, so the next acquire
+        // must fail rather than allocate a third.
+        assert!(pool.acquire().is_none());
+        // Returning one frees a slot for a fresh acquire.
+        pool.release(a.unwrap());
+        assert!(pool.acquire().is_some());
+    }
+
+    #[test]
+    fn release_over_capacity_drops_extra() {
+        let mut pool = ObjectPool::new(|| 0u32, 0, 2);
+        let a = pool.acquire().unwrap();
+        let b = pool.acquire().unwrap();
+        pool.release(a);
+        pool.release(b);
+        assert_eq!(pool.size(), 2);
+        // The pool is full; a further release is dropped rather than stored.
+        pool.release(99);
+        assert_eq!(pool.size(), 2);
+    }
+}
@@ -0,0 +1,206 @@
+//! The symbol records produced by the parser.
+
+/// The category of a discovered [`Symbol`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SymbolKind {
+    #[default]
+    Function,
+    Method,
+    Struct,
+    Enum,
+    Trait,
+    /// An `impl` block, either inherent or a trait implementation.
+    Impl,
+    /// A `type Name = ...;` alias.
+    TypeAlias,
+    /// An associated type inside a trait (`type Item;`) or impl
+    /// (`type Item = T;`).
+    AssocType,
+    /// An associated const inside a trait or impl (`const N: T = ...;`).
+    AssocConst,
+    /// A module-level `const NAME: T = ...;`.
+    Const,
+    /// A module-level `static [mut] NAME: T = ...;`.
+    Static,
+    /// A named field of a struct.
+    Field,
+    /// A variant of an enum.
+    Variant,
+}
+
+impl SymbolKind {
+    /// A stable lower-case label, used by the textual report.
+    pub fn label(self) -> &'static str {
+        match self {
+            SymbolKind::Function => "function",
+            SymbolKind::Method => "method",
+            SymbolKind::Struct => "struct",
+            SymbolKind::Enum => "enum",
+            SymbolKind::Trait => "trait",
+            SymbolKind::Impl => "impl",
+            SymbolKind::TypeAlias => "type",
+            SymbolKind::AssocType => "assoc type",
+            SymbolKind::AssocConst => "assoc const",
+            SymbolKind::Const => "const",
+            SymbolKind::Static => "static",
+            SymbolKind::Field => "field",
+            SymbolKind::Variant => "variant",
+        }
+    }
+}
+
+/// The visibility of an item.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Visibility {
+    /// No `pub` marker — private to the defining module.
+    #[default]
+    Private,
+    /// `pub`.
+    Public,
+    /// `pub(crate)`.
+    Crate,
+    /// A restricted visibility such as `pub(super)` or `pub(in a::b)`; holds
+    /// the text inside the parentheses.
+    Restricted(String),
+}
+
+impl Visibility {
+    /// A display label, e.g. `private`, `pub`, `pub(crate)`, `pub(super)`.
+    pub fn label(&self) -> String {
+        match self {
+            Visibility::Private => "private".to_string(),
+            Visibility::Public => "pub".to_string(),
+            Visibility::Crate => "pub(crate)".to_string(),
+            Visibility::Restricted(path) => format!("pub({path})"),
+        }
+    }
+}
+
+/// A single generic parameter declared in angle brackets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenericParam {
+    /// A lifetime parameter such as `'a` with its outlives bounds.
+    Lifetime { name: String, bounds: Vec<String> },
+    /// A type parameter such as `T: Fn() -> T + 'static`.
+    Type { name: String, bounds: Vec<String> },
+    /// A const parameter such as `const N: usize`.
+    Const { name: String, ty: String },
+}
+
+impl GenericParam {
+    /// The parameter name without its bounds (`'a`, `T`, `N`).
+    pub fn name(&self) -> &str {
+        match self {
+            GenericParam::Lifetime { name, .. }
+            | GenericParam::Type { name, .. }
+            | GenericParam::Const { name, .. } => name,
+        }
+    }
+
+    /// The parameter with its bounds rendered back to source form, e.g.
+    /// `T: Clone + 'static`, `'a: 'b`, or `const N: usize`.
+    pub fn render(&self) -> String {
+        match self {
+            GenericParam::Lifetime { name, bounds } | GenericParam::Type { name, bounds } => {
+                if bounds.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{name}: {}", bounds.join(" + "))
+                }
+            }
+            GenericParam::Const { name, ty } => format!("const {name}: {ty}"),
+        }
+    }
+}
+
+/// A single `where` predicate, e.g. `T: Clone + 'static`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WherePredicate {
+    /// The type or lifetime being constrained.
+    pub target: String,
+    /// The bounds applied to `target`.
+    pub bounds: Vec<String>,
+}
+
+/// The generic machinery attached to an item: its parameter list and the
+/// parsed `where` clause. An item with no generics has an empty [`Generics`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Generics {
+    pub params: Vec<GenericParam>,
+    pub where_clause: Vec<WherePredicate>,
+}
+
+impl Generics {
+    /// Whether the item declares no parameters and no predicates.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty() && self.where_clause.is_empty()
+    }
+
+    /// The `where` clause rendered back to source form, e.g.
+    /// `where T: Clone, U: 'static`. Empty when there are no predicates.
+    pub fn render_where(&self) -> String {
+        if self.where_clause.is_empty() {
+            return String::new();
+        }
+        let preds: Vec<_> = self
+            .where_clause
+            .iter()
+            .map(|p| {
+                if p.bounds.is_empty() {
+                    p.target.clone()
+                } else {
+                    format!("{}: {}", p.target, p.bounds.join(" + "))
+                }
+            })
+            .collect();
+        format!("where {}", preds.join(", "))
+    }
+}
+
+/// A symbol discovered by the parser.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: SymbolKind,
+    /// The declared name. For an `impl` block this is the implementing type,
+    /// e.g. `Point1` or `ObjectPool`.
+    pub name: String,
+    /// The item's visibility.
+    pub visibility: Visibility,
+    /// Generic parameters, bounds, and `where` clause attached to the item.
+    pub generics: Generics,
+    /// For a trait `impl`, the trait being implemented (`Pool`, `Printable`).
+    /// `None` for inherent impls and every other kind.
+    pub trait_name: Option<String>,
+    /// The generic arguments applied to the implementing type in an `impl`
+    /// header, e.g. `["i32", "i32"]` for `impl Point1<i32, i32>`. Empty when
+    /// the type is not parameterised at the use site.
+    pub type_args: Vec<String>,
+    /// The declared type, for items that carry one: an associated const's
+    /// type, or an associated type's bound (`type Item: Clone`).
+    pub ty: Option<String>,
+    /// The right-hand side of a binding or initializer, e.g. `T` in
+    /// `type Item = T;` or `42` in `const N: u8 = 42;`.
+    pub value: Option<String>,
+    /// Whether the item is a `static mut`. Always false for non-statics.
+    pub mutable: bool,
+    /// Whether the item carries `unsafe`: a `static mut`, an `unsafe fn`, or a
+    /// `fn` whose body contains an `unsafe { ... }` block. Lets downstream
+    /// tools flag functions that may mutate static state.
+    pub is_unsafe: bool,
+    /// Items nested under this one: for a trait or impl, its associated types
+    /// and consts.
+    pub children: Vec<Symbol>,
+    /// Byte offset of the item's first token.
+    pub start: usize,
+    /// Byte offset one past the item's closing brace or semicolon.
+    pub end: usize,
+}
+
+impl Symbol {
+    /// The base type this symbol attaches to, ignoring generic arguments. For
+    /// both `impl<T> Point1<T, U>` and `impl Point1<i32, i32>` this is
+    /// `Point1`, so the two can be linked to the same declared type.
+    pub fn base_type(&self) -> &str {
+        &self.name
+    }
+}